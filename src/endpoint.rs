@@ -29,13 +29,50 @@ impl std::str::FromStr for Endpoint {
         if s.starts_with("http://") || s.starts_with("https://") {
             Ok(Endpoint::Http(s.into()))
         } else if let Some(path) = s.strip_prefix("unix://") {
-            Ok(Endpoint::Unix(path.into(), None))
+            match path.split_once(':') {
+                Some((path, extra)) => Ok(Endpoint::Unix(path.into(), Some(extra.into()))),
+                None => Ok(Endpoint::Unix(path.into(), None)),
+            }
         } else {
-            Err(Error)
+            Err(Error::UnsupportedScheme)
         }
     }
 }
 
+/// Renders the endpoint back to its canonical URI form so that
+/// `endpoint.to_string().parse::<Endpoint>()` round-trips.
+impl std::fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Endpoint::Http(url) => write!(f, "{url}"),
+            Endpoint::Unix(path, None) => write!(f, "unix://{path}"),
+            Endpoint::Unix(path, Some(extra)) => write!(f, "unix://{path}:{extra}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Endpoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Endpoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +94,18 @@ mod tests {
         let endpoint: Endpoint = "unix:///tmp/socket".parse().unwrap();
         assert_eq!(endpoint, Endpoint::Unix("/tmp/socket".into(), None));
     }
+
+    #[test]
+    fn display_round_trip() {
+        for uri in [
+            "https://localhost",
+            "http://localhost",
+            "unix:///tmp/socket",
+            "unix:///tmp/socket:extra",
+        ] {
+            let endpoint: Endpoint = uri.parse().unwrap();
+            assert_eq!(endpoint.to_string(), uri);
+            assert_eq!(endpoint.to_string().parse::<Endpoint>().unwrap(), endpoint);
+        }
+    }
 }
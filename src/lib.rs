@@ -2,13 +2,20 @@
 #![deny(missing_debug_implementations)]
 #![deny(missing_docs)]
 
+mod endpoint;
 mod service;
 
 use std::io;
 use std::num::ParseIntError;
 
+pub use endpoint::Endpoint;
+#[cfg(feature = "tokio")]
+pub use service::AsyncListener;
+#[cfg(feature = "tokio")]
+pub use service::AsyncStream;
 pub use service::Binding;
 pub use service::Listener;
+pub use service::Socket;
 pub use service::Stream;
 
 /// Errors while processing service listeners.
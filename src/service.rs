@@ -1,14 +1,28 @@
 use std::env::var;
+#[cfg(all(target_os = "linux", feature = "vsock"))]
+use std::io;
 use std::net::SocketAddr;
 use std::net::TcpListener;
 use std::net::TcpStream;
 use std::net::ToSocketAddrs;
+use std::net::UdpSocket;
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+#[cfg(feature = "tls")]
+use std::sync::Arc;
+#[cfg(target_os = "linux")]
+use std::os::linux::net::SocketAddrExt;
+#[cfg(target_os = "linux")]
+use std::os::unix::ffi::OsStrExt;
 #[cfg(unix)]
 use std::os::unix::net::UnixListener;
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
 
+#[cfg(all(target_os = "linux", feature = "vsock"))]
+use vsock::{VsockListener, VsockStream, VMADDR_CID_ANY};
+
 use super::Error;
 
 const SD_LISTEN_FDS_START: i32 = 3;
@@ -39,16 +53,103 @@ pub enum Binding {
     /// descriptor. This mechanism is used by the socket activation.
     FileDescriptor(i32),
 
+    /// The service should be bound to these explicit, opened file
+    /// descriptors. This is used by socket activation frameworks (such
+    /// as systemd or launchd) that advertise several sockets for one
+    /// service.
+    FileDescriptors(Vec<i32>),
+
     /// The service should be bound to a Unix domain socket file under
     /// specified path.
     FilePath(PathBuf),
 
+    /// The service should be bound to a Unix domain socket in the
+    /// abstract namespace, identified by `name`. Parsed from
+    /// `unix://@name` or `unix:///@name`. Abstract sockets have no
+    /// filesystem representation and are reclaimed automatically once
+    /// the last reference to them is closed. Linux-only.
+    #[cfg(target_os = "linux")]
+    AbstractSocket(std::ffi::OsString),
+
     /// The service should be bound to the first TCP socket that succeed
     /// the binding.
     Sockets(Vec<SocketAddr>),
 
+    /// The service should be bound to the first UDP socket that
+    /// succeeds the binding.
+    Datagram(Vec<SocketAddr>),
+
     /// Windows Named Pipe.
     NamedPipe(std::ffi::OsString),
+
+    /// The service should be bound to this `AF_VSOCK` context ID and
+    /// port, for VM-to-host communication. Only available on Linux
+    /// with the `vsock` feature enabled.
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    Vsock {
+        /// Context ID of the guest or host, or `VMADDR_CID_ANY` to
+        /// accept connections from any guest.
+        cid: u32,
+        /// Port number.
+        port: u32,
+    },
+
+    /// The service should be bound to this TCP address and the
+    /// resulting connections wrapped in TLS. Parsed from a `tcps://`
+    /// URI. Unlike `Sockets` this cannot become a
+    /// [`Listener`]/[`Stream`] by itself: attach a certificate/key
+    /// configuration first via [`Binding::with_tls`] (listener side)
+    /// or [`Binding::with_tls_client`] (stream side).
+    #[cfg(feature = "tls")]
+    Tls(Vec<SocketAddr>),
+}
+
+impl Binding {
+    /// Attaches a TLS server configuration to this binding so that
+    /// converting the result into a [`Listener`] produces a
+    /// TLS-terminating listener instead of a plaintext one.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(self, config: rustls::ServerConfig) -> TlsBinding {
+        TlsBinding {
+            binding: self,
+            config: Arc::new(config),
+        }
+    }
+
+    /// Attaches a TLS client configuration and the target server name
+    /// to this binding so that converting the result into a [`Stream`]
+    /// performs a TLS handshake over the connected TCP socket.
+    #[cfg(feature = "tls")]
+    pub fn with_tls_client(
+        self,
+        config: rustls::ClientConfig,
+        server_name: rustls::pki_types::ServerName<'static>,
+    ) -> TlsStreamBinding {
+        TlsStreamBinding {
+            binding: self,
+            config: Arc::new(config),
+            server_name,
+        }
+    }
+}
+
+/// A [`Binding`] paired with a TLS server configuration, produced by
+/// [`Binding::with_tls`].
+#[cfg(feature = "tls")]
+#[derive(Debug, Clone)]
+pub struct TlsBinding {
+    binding: Binding,
+    config: Arc<rustls::ServerConfig>,
+}
+
+/// A [`Binding`] paired with a TLS client configuration and server
+/// name, produced by [`Binding::with_tls_client`].
+#[cfg(feature = "tls")]
+#[derive(Debug, Clone)]
+pub struct TlsStreamBinding {
+    binding: Binding,
+    config: Arc<rustls::ClientConfig>,
+    server_name: rustls::pki_types::ServerName<'static>,
 }
 
 impl From<PathBuf> for Binding {
@@ -63,6 +164,80 @@ impl From<SocketAddr> for Binding {
     }
 }
 
+/// Renders the binding back to its canonical URI form so that
+/// `binding.to_string().parse::<Binding>()` round-trips.
+///
+/// Note that `Sockets` and `Datagram` resolve a hostname into concrete
+/// addresses when parsed, so `Display` renders the first resolved
+/// address rather than the original hostname; similarly
+/// `FileDescriptors` renders only the first descriptor. Parsing a
+/// single address or descriptor always yields a single-element
+/// binding, so this keeps the round-trip lossless. An empty vector
+/// (not producible by parsing, but constructible directly) renders as
+/// an empty string.
+///
+/// `NamedPipe` renders in its canonical `npipe://name` form when the
+/// stored path is the usual `\\.\pipe\name`; any other (e.g. UNC)
+/// path is rendered verbatim.
+impl std::fmt::Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Binding::FileDescriptor(fd) => write!(f, "fd://{fd}"),
+            Binding::FileDescriptors(fds) => match fds.first() {
+                Some(fd) => write!(f, "fd://{fd}"),
+                None => Ok(()),
+            },
+            Binding::FilePath(path) => write!(f, "unix://{}", path.display()),
+            #[cfg(target_os = "linux")]
+            Binding::AbstractSocket(name) => write!(f, "unix://@{}", name.to_string_lossy()),
+            Binding::Sockets(addrs) => match addrs.first() {
+                Some(addr) => write!(f, "tcp://{addr}"),
+                None => Ok(()),
+            },
+            Binding::Datagram(addrs) => match addrs.first() {
+                Some(addr) => write!(f, "udp://{addr}"),
+                None => Ok(()),
+            },
+            Binding::NamedPipe(pipe) => {
+                let pipe = pipe.to_string_lossy();
+                match pipe.strip_prefix(r"\\.\pipe\") {
+                    Some(name) => write!(f, "npipe://{name}"),
+                    None => write!(f, "{pipe}"),
+                }
+            }
+            #[cfg(all(target_os = "linux", feature = "vsock"))]
+            Binding::Vsock { cid, port } => write!(f, "vsock://{cid}:{port}"),
+            #[cfg(feature = "tls")]
+            Binding::Tls(addrs) => match addrs.first() {
+                Some(addr) => write!(f, "tcps://{addr}"),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 /// Opened service listener.
 ///
 /// This structure contains an already open listener. Note that the
@@ -89,6 +264,80 @@ pub enum Listener {
 
     /// Named Pipe.
     NamedPipe(std::ffi::OsString),
+
+    /// Listener for an `AF_VSOCK` socket.
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    Vsock(VsockListener),
+
+    /// Listener for a TLS-terminating TCP socket, produced from a
+    /// [`TlsBinding`].
+    #[cfg(feature = "tls")]
+    Tls(TcpListener, Arc<rustls::ServerConfig>),
+}
+
+#[cfg(feature = "tls")]
+impl TryFrom<TlsBinding> for Listener {
+    type Error = std::io::Error;
+
+    fn try_from(value: TlsBinding) -> Result<Self, Self::Error> {
+        let addrs = match value.binding {
+            Binding::Tls(addrs) | Binding::Sockets(addrs) => addrs,
+            _ => return Err(std::io::Error::other(Error::UnsupportedScheme)),
+        };
+
+        let listener = TcpListener::bind(&*addrs)?;
+        while let Err(e) = listener.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Ok(Listener::Tls(listener, value.config))
+    }
+}
+
+#[cfg(feature = "tls")]
+impl Listener {
+    /// Accepts a pending connection on a [`Listener::Tls`] listener and
+    /// wraps it in a server-side TLS stream, performing the handshake
+    /// as data is read from or written to the returned stream.
+    ///
+    /// Returns a [`crate::Error::UnsupportedScheme`] error if called on
+    /// any other [`Listener`] variant.
+    pub fn accept_tls(
+        &self,
+    ) -> std::io::Result<rustls::StreamOwned<rustls::ServerConnection, TcpStream>> {
+        let Listener::Tls(listener, config) = self else {
+            return Err(std::io::Error::other(Error::UnsupportedScheme));
+        };
+
+        let (stream, _addr) = listener.accept()?;
+        while let Err(e) = stream.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        let conn = rustls::ServerConnection::new(config.clone()).map_err(std::io::Error::other)?;
+
+        Ok(rustls::StreamOwned::new(conn, stream))
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "vsock"))]
+impl From<VsockListener> for Listener {
+    fn from(listener: VsockListener) -> Self {
+        while let Err(e) = listener.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Listener::Vsock(listener)
+    }
 }
 
 #[cfg(unix)]
@@ -144,6 +393,55 @@ pub enum Stream {
 
     /// Named Pipe.
     NamedPipe(std::ffi::OsString),
+
+    /// Stream for an `AF_VSOCK` socket.
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    Vsock(VsockStream),
+
+    /// Stream for a TLS-wrapped TCP socket, produced from a
+    /// [`TlsStreamBinding`].
+    #[cfg(feature = "tls")]
+    Tls(rustls::StreamOwned<rustls::ClientConnection, TcpStream>),
+}
+
+#[cfg(feature = "tls")]
+impl TryFrom<TlsStreamBinding> for Stream {
+    type Error = std::io::Error;
+
+    fn try_from(value: TlsStreamBinding) -> Result<Self, Self::Error> {
+        let addrs = match value.binding {
+            Binding::Tls(addrs) | Binding::Sockets(addrs) => addrs,
+            _ => return Err(std::io::Error::other(Error::UnsupportedScheme)),
+        };
+
+        let stream = TcpStream::connect(&*addrs)?;
+        let conn = rustls::ClientConnection::new(value.config, value.server_name)
+            .map_err(std::io::Error::other)?;
+        let tls = rustls::StreamOwned::new(conn, stream);
+
+        while let Err(e) = tls.sock.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Ok(Stream::Tls(tls))
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "vsock"))]
+impl From<VsockStream> for Stream {
+    fn from(stream: VsockStream) -> Self {
+        while let Err(e) = stream.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Stream::Vsock(stream)
+    }
 }
 
 #[cfg(unix)]
@@ -173,6 +471,58 @@ impl From<TcpStream> for Stream {
     }
 }
 
+/// Opened connectionless (datagram) service socket.
+///
+/// This structure contains an already bound socket. Note that the
+/// sockets are set to non-blocking mode.
+///
+/// # Examples
+///
+/// ```
+/// # use service_binding::{Binding, Socket};
+/// # fn main() -> testresult::TestResult {
+/// let binding: Binding = "udp://127.0.0.1:8080".parse()?;
+/// let socket = binding.try_into()?;
+/// assert!(matches!(socket, Socket::Udp(_)));
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub enum Socket {
+    /// Datagram socket for a Unix domain socket.
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+
+    /// Datagram socket for UDP.
+    Udp(UdpSocket),
+}
+
+#[cfg(unix)]
+impl From<UnixDatagram> for Socket {
+    fn from(socket: UnixDatagram) -> Self {
+        while let Err(e) = socket.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Socket::Unix(socket)
+    }
+}
+
+impl From<UdpSocket> for Socket {
+    fn from(socket: UdpSocket) -> Self {
+        while let Err(e) = socket.set_nonblocking(true) {
+            // retry WouldBlock errors
+            if e.kind() != std::io::ErrorKind::WouldBlock {
+                break;
+            }
+        }
+
+        Socket::Udp(socket)
+    }
+}
+
 impl<'a> std::convert::TryFrom<&'a str> for Binding {
     type Error = Error;
 
@@ -182,12 +532,15 @@ impl<'a> std::convert::TryFrom<&'a str> for Binding {
                 if let Ok(fds) = var("LISTEN_FDS") {
                     let fds: i32 = fds.parse()?;
 
-                    // we support only one socket for now
-                    if fds != 1 {
+                    if fds < 1 {
                         return Err(Error::DescriptorOutOfRange(fds));
+                    } else if fds == 1 {
+                        return Ok(Binding::FileDescriptor(SD_LISTEN_FDS_START));
                     }
 
-                    return Ok(Binding::FileDescriptor(SD_LISTEN_FDS_START));
+                    return Ok(Binding::FileDescriptors(
+                        (SD_LISTEN_FDS_START..SD_LISTEN_FDS_START + fds).collect(),
+                    ));
                 } else {
                     return Err(Error::DescriptorsMissing);
                 }
@@ -198,27 +551,36 @@ impl<'a> std::convert::TryFrom<&'a str> for Binding {
             #[cfg(target_os = "macos")]
             {
                 let fds = raunch::activate_socket(name).map_err(|_| Error::DescriptorsMissing)?;
-                if fds.len() == 1 {
-                    Ok(Binding::FileDescriptor(fds[0]))
-                } else {
-                    Err(Error::DescriptorOutOfRange(fds.len() as i32))
+                match fds.len() {
+                    0 => Err(Error::DescriptorOutOfRange(0)),
+                    1 => Ok(Binding::FileDescriptor(fds[0])),
+                    _ => Ok(Binding::FileDescriptors(fds.to_vec())),
                 }
             }
             #[cfg(not(target_os = "macos"))]
             {
                 if let (Ok(names), Ok(fds)) = (var("LISTEN_FDNAMES"), var("LISTEN_FDS")) {
                     let fds: usize = fds.parse()?;
-                    for (fd_index, fd_name) in names.split(':').enumerate() {
-                        if fd_name == name && fd_index < fds {
-                            return Ok(Binding::FileDescriptor(
-                                SD_LISTEN_FDS_START + fd_index as i32,
-                            ));
-                        }
-                    }
+                    let matches: Vec<i32> = names
+                        .split(':')
+                        .enumerate()
+                        .filter(|(fd_index, fd_name)| *fd_name == name && *fd_index < fds)
+                        .map(|(fd_index, _)| SD_LISTEN_FDS_START + fd_index as i32)
+                        .collect();
+
+                    return match matches.len() {
+                        0 => Err(Error::DescriptorsMissing),
+                        1 => Ok(Binding::FileDescriptor(matches[0])),
+                        _ => Ok(Binding::FileDescriptors(matches)),
+                    };
                 }
                 Err(Error::DescriptorsMissing)
             }
         } else if let Some(file) = s.strip_prefix("unix://") {
+            #[cfg(target_os = "linux")]
+            if let Some(name) = file.strip_prefix('@').or_else(|| file.strip_prefix("/@")) {
+                return Ok(Binding::AbstractSocket(name.into()));
+            }
             Ok(Binding::FilePath(file.into()))
         } else if let Some(file) = s.strip_prefix("npipe://") {
             if let Some('.' | '/' | '\\') = file.chars().next() {
@@ -231,6 +593,59 @@ impl<'a> std::convert::TryFrom<&'a str> for Binding {
                 Ok(addrs) => Ok(Binding::Sockets(addrs.collect())),
                 Err(err) => return Err(Error::BadAddress(err)),
             }
+        } else if let Some(addr) = s.strip_prefix("udp://") {
+            match addr.to_socket_addrs() {
+                Ok(addrs) => Ok(Binding::Datagram(addrs.collect())),
+                Err(err) => Err(Error::BadAddress(err)),
+            }
+        } else if let Some(addr) = s.strip_prefix("tcps://") {
+            #[cfg(feature = "tls")]
+            {
+                match addr.to_socket_addrs() {
+                    Ok(addrs) => Ok(Binding::Tls(addrs.collect())),
+                    Err(err) => Err(Error::BadAddress(err)),
+                }
+            }
+            #[cfg(not(feature = "tls"))]
+            {
+                let _ = addr;
+                Err(Error::UnsupportedScheme)
+            }
+        } else if let Some(addr) = s.strip_prefix("vsock://") {
+            #[cfg(all(target_os = "linux", feature = "vsock"))]
+            {
+                let (cid, port) = addr.split_once(':').ok_or_else(|| {
+                    Error::BadAddress(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "missing vsock port",
+                    ))
+                })?;
+
+                let cid = if cid == "-1" {
+                    VMADDR_CID_ANY
+                } else {
+                    cid.parse().map_err(|_| {
+                        Error::BadAddress(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "invalid vsock context id",
+                        ))
+                    })?
+                };
+
+                let port = port.parse().map_err(|_| {
+                    Error::BadAddress(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "invalid vsock port",
+                    ))
+                })?;
+
+                Ok(Binding::Vsock { cid, port })
+            }
+            #[cfg(not(all(target_os = "linux", feature = "vsock")))]
+            {
+                let _ = addr;
+                Err(Error::UnsupportedScheme)
+            }
         } else if s.starts_with(r"\\") {
             Ok(Binding::NamedPipe(s.into()))
         } else {
@@ -259,13 +674,36 @@ impl TryFrom<Binding> for Listener {
                 Ok(unsafe { UnixListener::from_raw_fd(descriptor) }.into())
             }
             #[cfg(unix)]
+            Binding::FileDescriptors(descriptors) => {
+                use std::os::unix::io::FromRawFd;
+
+                let descriptor = *descriptors
+                    .first()
+                    .ok_or(Error::DescriptorsMissing)
+                    .map_err(std::io::Error::other)?;
+
+                Ok(unsafe { UnixListener::from_raw_fd(descriptor) }.into())
+            }
+            #[cfg(unix)]
             Binding::FilePath(path) => {
                 // ignore errors if the file does not exist
                 let _ = std::fs::remove_file(&path);
                 Ok(UnixListener::bind(path)?.into())
             }
+            #[cfg(target_os = "linux")]
+            Binding::AbstractSocket(name) => {
+                let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())?;
+                Ok(UnixListener::bind_addr(&addr)?.into())
+            }
             Binding::Sockets(sockets) => Ok(std::net::TcpListener::bind(&*sockets)?.into()),
+            Binding::Datagram(_) => Err(std::io::Error::other(Error::UnsupportedScheme)),
             Binding::NamedPipe(pipe) => Ok(Listener::NamedPipe(pipe)),
+            #[cfg(all(target_os = "linux", feature = "vsock"))]
+            Binding::Vsock { cid, port } => {
+                Ok(VsockListener::bind_with_cid_port(cid, port)?.into())
+            }
+            #[cfg(feature = "tls")]
+            Binding::Tls(_) => Err(std::io::Error::other(Error::UnsupportedScheme)),
             #[cfg(not(unix))]
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
@@ -275,6 +713,26 @@ impl TryFrom<Binding> for Listener {
     }
 }
 
+impl TryFrom<Binding> for Vec<Listener> {
+    type Error = std::io::Error;
+
+    /// Converts a binding that may describe several socket-activation
+    /// descriptors (`fd://` with `LISTEN_FDS` > 1, or an `fd://name`
+    /// matching several entries) into one [`Listener`] per descriptor.
+    /// Every other binding yields a single-element vector, going
+    /// through the regular [`TryFrom<Binding> for Listener`] conversion.
+    fn try_from(value: Binding) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(unix)]
+            Binding::FileDescriptors(descriptors) => descriptors
+                .into_iter()
+                .map(|descriptor| Binding::FileDescriptor(descriptor).try_into())
+                .collect(),
+            other => Ok(vec![other.try_into()?]),
+        }
+    }
+}
+
 impl TryFrom<Binding> for Stream {
     type Error = std::io::Error;
 
@@ -287,9 +745,32 @@ impl TryFrom<Binding> for Stream {
                 Ok(unsafe { UnixStream::from_raw_fd(descriptor) }.into())
             }
             #[cfg(unix)]
+            Binding::FileDescriptors(descriptors) => {
+                use std::os::unix::io::FromRawFd;
+
+                let descriptor = *descriptors
+                    .first()
+                    .ok_or(Error::DescriptorsMissing)
+                    .map_err(std::io::Error::other)?;
+
+                Ok(unsafe { UnixStream::from_raw_fd(descriptor) }.into())
+            }
+            #[cfg(unix)]
             Binding::FilePath(path) => Ok(UnixStream::connect(path)?.into()),
+            #[cfg(target_os = "linux")]
+            Binding::AbstractSocket(name) => {
+                let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())?;
+                Ok(UnixStream::connect_addr(&addr)?.into())
+            }
             Binding::Sockets(sockets) => Ok(std::net::TcpStream::connect(&*sockets)?.into()),
+            Binding::Datagram(_) => Err(std::io::Error::other(Error::UnsupportedScheme)),
             Binding::NamedPipe(pipe) => Ok(Self::NamedPipe(pipe)),
+            #[cfg(all(target_os = "linux", feature = "vsock"))]
+            Binding::Vsock { cid, port } => {
+                Ok(VsockStream::connect_with_cid_port(cid, port)?.into())
+            }
+            #[cfg(feature = "tls")]
+            Binding::Tls(_) => Err(std::io::Error::other(Error::UnsupportedScheme)),
             #[cfg(not(unix))]
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
@@ -299,6 +780,124 @@ impl TryFrom<Binding> for Stream {
     }
 }
 
+impl TryFrom<Binding> for Socket {
+    type Error = std::io::Error;
+
+    fn try_from(value: Binding) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(unix)]
+            Binding::FilePath(path) => {
+                // ignore errors if the file does not exist
+                let _ = std::fs::remove_file(&path);
+                Ok(UnixDatagram::bind(path)?.into())
+            }
+            Binding::Datagram(addrs) => Ok(UdpSocket::bind(&*addrs)?.into()),
+            _ => Err(std::io::Error::other(Error::UnsupportedScheme)),
+        }
+    }
+}
+
+/// Opened async service listener.
+///
+/// Like [`Listener`], but registered with the `tokio` runtime and
+/// ready to `.accept().await`. Requires the `tokio` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use service_binding::{AsyncListener, Binding};
+/// # async fn example() -> testresult::TestResult {
+/// let binding: Binding = "tcp://127.0.0.1:8080".parse()?;
+/// let listener: AsyncListener = binding.try_into()?;
+/// assert!(matches!(listener, AsyncListener::Tcp(_)));
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub enum AsyncListener {
+    /// Listener for a Unix domain socket.
+    #[cfg(unix)]
+    Unix(tokio::net::UnixListener),
+
+    /// Listener for a TCP socket.
+    Tcp(tokio::net::TcpListener),
+}
+
+#[cfg(feature = "tokio")]
+impl TryFrom<Listener> for AsyncListener {
+    type Error = std::io::Error;
+
+    fn try_from(value: Listener) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(unix)]
+            Listener::Unix(listener) => Ok(Self::Unix(tokio::net::UnixListener::from_std(listener)?)),
+            Listener::Tcp(listener) => Ok(Self::Tcp(tokio::net::TcpListener::from_std(listener)?)),
+            _ => Err(std::io::Error::other(Error::UnsupportedScheme)),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl TryFrom<Binding> for AsyncListener {
+    type Error = std::io::Error;
+
+    fn try_from(value: Binding) -> Result<Self, Self::Error> {
+        let listener: Listener = value.try_into()?;
+        listener.try_into()
+    }
+}
+
+/// Opened async client service connection.
+///
+/// Like [`Stream`], but registered with the `tokio` runtime and ready
+/// for `.read()`/`.write()` use from async code. Requires the `tokio`
+/// feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use service_binding::{AsyncStream, Binding};
+/// # async fn example() -> testresult::TestResult {
+/// let binding: Binding = "tcp://127.0.0.1:8080".parse()?;
+/// let stream: AsyncStream = binding.try_into()?;
+/// assert!(matches!(stream, AsyncStream::Tcp(_)));
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub enum AsyncStream {
+    /// Stream for a Unix domain socket.
+    #[cfg(unix)]
+    Unix(tokio::net::UnixStream),
+
+    /// Stream for a TCP socket.
+    Tcp(tokio::net::TcpStream),
+}
+
+#[cfg(feature = "tokio")]
+impl TryFrom<Stream> for AsyncStream {
+    type Error = std::io::Error;
+
+    fn try_from(value: Stream) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(unix)]
+            Stream::Unix(stream) => Ok(Self::Unix(tokio::net::UnixStream::from_std(stream)?)),
+            Stream::Tcp(stream) => Ok(Self::Tcp(tokio::net::TcpStream::from_std(stream)?)),
+            _ => Err(std::io::Error::other(Error::UnsupportedScheme)),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl TryFrom<Binding> for AsyncStream {
+    type Error = std::io::Error;
+
+    fn try_from(value: Binding) -> Result<Self, Self::Error> {
+        let stream: Stream = value.try_into()?;
+        stream.try_into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(unix)]
@@ -363,6 +962,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    #[serial]
+    fn parse_fd_named_multiple() -> TestResult {
+        std::env::set_var("LISTEN_FDS", "3");
+        std::env::set_var("LISTEN_FDNAMES", "web:web:other");
+        let binding = "fd://web".parse()?;
+        assert_eq!(Binding::FileDescriptors(vec![3, 4]), binding);
+        std::env::remove_var("LISTEN_FDNAMES");
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn parse_fd_bad() -> TestResult {
@@ -397,11 +1009,42 @@ mod tests {
 
     #[test]
     #[serial]
-    fn parse_fd_fail_unsupported_fds_count() -> TestResult {
+    fn parse_fd_multiple() -> TestResult {
         std::env::set_var("LISTEN_FDS", "3");
+        let binding = Binding::from_str("fd://")?;
+        assert_eq!(Binding::FileDescriptors(vec![3, 4, 5]), binding);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[serial]
+    fn fds_to_listeners() -> TestResult {
+        let first = tempfile::tempfile()?.into_raw_fd();
+        let second = tempfile::tempfile()?.into_raw_fd();
+        let binding = Binding::FileDescriptors(vec![first, second]);
+
+        let listeners: Vec<Listener> = binding.try_into()?;
+        assert_eq!(2, listeners.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tcp_to_listeners_vec() -> TestResult {
+        let binding: Binding = "tcp://127.0.0.1:8082".try_into()?;
+        let listeners: Vec<Listener> = binding.try_into()?;
+        assert_eq!(1, listeners.len());
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn parse_fd_fail_out_of_range() -> TestResult {
+        std::env::set_var("LISTEN_FDS", "0");
         assert!(matches!(
             Binding::from_str("fd://"),
-            Err(Error::DescriptorOutOfRange(3))
+            Err(Error::DescriptorOutOfRange(0))
         ));
         Ok(())
     }
@@ -444,6 +1087,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_unix_abstract() -> TestResult {
+        let binding = "unix://@test-abstract".try_into()?;
+        assert_eq!(Binding::AbstractSocket("test-abstract".into()), binding);
+
+        let binding2 = "unix:///@test-abstract".try_into()?;
+        assert_eq!(binding, binding2);
+
+        let listener: Listener = binding.try_into()?;
+        assert!(matches!(listener, Listener::Unix(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn display_round_trip_unix_abstract() -> TestResult {
+        let uri = "unix://@test-abstract-round-trip";
+        let binding = Binding::from_str(uri)?;
+        assert_eq!(binding.to_string(), uri);
+        assert_eq!(binding.to_string().parse::<Binding>()?, binding);
+        Ok(())
+    }
+
     #[test]
     fn parse_tcp() -> TestResult {
         let binding = "tcp://127.0.0.1:8081".try_into()?;
@@ -493,6 +1161,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "tls")]
+    fn parse_tcps() -> TestResult {
+        let binding = "tcps://127.0.0.1:8443".try_into()?;
+        assert_eq!(Binding::Tls(vec![([127, 0, 0, 1], 8443).into()]), binding);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tokio")]
+    async fn tcp_to_async_listener() -> TestResult {
+        let binding: Binding = "tcp://127.0.0.1:0".try_into()?;
+        let listener: AsyncListener = binding.try_into()?;
+        assert!(matches!(listener, AsyncListener::Tcp(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tokio")]
+    async fn tcp_to_async_stream() -> TestResult {
+        let server = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let addr = server.local_addr()?;
+
+        let binding: Binding = format!("tcp://{addr}").try_into()?;
+        let stream: AsyncStream = binding.try_into()?;
+        assert!(matches!(stream, AsyncStream::Tcp(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_udp() -> TestResult {
+        let binding = "udp://127.0.0.1:8081".try_into()?;
+        assert_eq!(Binding::Datagram(vec![([127, 0, 0, 1], 8081).into()]), binding);
+
+        let socket: Socket = binding.try_into()?;
+        assert!(matches!(socket, Socket::Udp(_)));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn unix_datagram() -> TestResult {
+        let dir = std::env::temp_dir().join("temp-datagram-socket");
+        let binding = Binding::FilePath(dir);
+        let socket: Socket = binding.try_into()?;
+        assert!(matches!(socket, Socket::Unix(_)));
+        Ok(())
+    }
+
     #[test]
     fn parse_pipe() -> TestResult {
         let binding = r"\\.\pipe\test".try_into()?;
@@ -559,6 +1276,86 @@ mod tests {
         assert!(matches!(binding, Binding::FilePath(_)));
     }
 
+    #[test]
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    fn parse_vsock() -> TestResult {
+        let binding = "vsock://2:1234".try_into()?;
+        assert_eq!(Binding::Vsock { cid: 2, port: 1234 }, binding);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    fn parse_vsock_any_cid() -> TestResult {
+        let binding = "vsock://-1:1234".try_into()?;
+        assert_eq!(
+            Binding::Vsock {
+                cid: VMADDR_CID_ANY,
+                port: 1234
+            },
+            binding
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", feature = "vsock"))]
+    fn parse_vsock_bad() -> TestResult {
+        assert!(matches!(
+            Binding::try_from("vsock://not-a-cid:1234"),
+            Err(Error::BadAddress(_))
+        ));
+
+        assert!(matches!(
+            Binding::try_from("vsock://2"),
+            Err(Error::BadAddress(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for uri in [
+            "fd://3",
+            "unix:///tmp/test",
+            "tcp://127.0.0.1:8081",
+            "udp://127.0.0.1:8081",
+            "npipe://test",
+        ] {
+            let binding = Binding::from_str(uri).unwrap();
+            assert_eq!(binding.to_string(), uri);
+            assert_eq!(binding.to_string().parse::<Binding>().unwrap(), binding);
+        }
+    }
+
+    #[test]
+    fn display_round_trip_multi_value() {
+        let fds = Binding::FileDescriptors(vec![3, 4, 5]);
+        assert_eq!(fds.to_string(), "fd://3");
+        assert_eq!(
+            fds.to_string().parse::<Binding>().unwrap(),
+            Binding::FileDescriptor(3)
+        );
+
+        let addr1 = SocketAddr::from(([127, 0, 0, 1], 8081));
+        let addr2 = SocketAddr::from(([127, 0, 0, 2], 8081));
+
+        let sockets = Binding::Sockets(vec![addr1, addr2]);
+        assert_eq!(sockets.to_string(), format!("tcp://{addr1}"));
+        assert_eq!(
+            sockets.to_string().parse::<Binding>().unwrap(),
+            Binding::Sockets(vec![addr1])
+        );
+
+        let datagram = Binding::Datagram(vec![addr1, addr2]);
+        assert_eq!(datagram.to_string(), format!("udp://{addr1}"));
+        assert_eq!(
+            datagram.to_string().parse::<Binding>().unwrap(),
+            Binding::Datagram(vec![addr1])
+        );
+    }
+
     #[test]
     fn convert_from_socket() {
         let socket: SocketAddr = ([127, 0, 0, 1], 8080).into();